@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::borrow::Borrow;
-use std::io::{Read, BufRead};
+use std::io::{Read, BufRead, Write};
 use std::net::SocketAddr;
 use std::path::Path;
 use std::sync::Arc;
@@ -45,27 +45,468 @@ use databend_query::common::HashMap;
 use std::collections::BTreeMap;
 use crate::cmds::queries::query::{build_query_endpoint, execute_query_json};
 use reqwest::Client;
-use common_base::tokio::io::{BufReader, AsyncBufReadExt, AsyncRead};
-use rayon::prelude::*;
+use common_base::tokio::io::{BufReader, AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, ReadBuf};
 use futures::StreamExt;
 use common_base::tokio::fs::File;
+use arrow2::io::parquet::read as parquet_read;
+use arrow2::io::avro::read as avro_read;
+use arrow2::array::Array;
+use futures::stream;
+use glob::glob;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll};
+use tokio_util::io::StreamReader;
 
-// Support different file format to be loaded
+// Support different file formats to be loaded. Row-oriented formats (CSV, NDJSON) are
+// parsed a line at a time off the input stream; columnar formats (Parquet, Avro) are
+// decoded a record batch at a time and each row is re-assembled into an INSERT tuple.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum FileFormat {
-    CSV,
+    Csv,
+    Parquet,
+    NdJson,
+    Avro,
+}
+
+impl FileFormat {
+    /// Row-oriented formats can be split into INSERT tuples line by line; columnar
+    /// formats need a whole record batch decoded before a single row is available.
+    pub fn is_row_oriented(&self) -> bool {
+        matches!(self, FileFormat::Csv | FileFormat::NdJson)
+    }
+
+    /// File extensions (lowercase, no leading dot) accepted for `--format` on every local file
+    /// a `--load` path, directory or glob expands to, so a stray mismatched file fails fast
+    /// with a clear error instead of a confusing per-row parse error partway through the load.
+    fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            FileFormat::Csv => &["csv"],
+            FileFormat::Parquet => &["parquet"],
+            FileFormat::NdJson => &["ndjson", "jsonl"],
+            FileFormat::Avro => &["avro"],
+        }
+    }
 }
 
 impl FromStr for FileFormat {
-    type Err = &'static str;
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<FileFormat, String> {
+        match s.to_ascii_lowercase().as_str() {
+            "csv" => Ok(FileFormat::Csv),
+            "parquet" => Ok(FileFormat::Parquet),
+            "ndjson" | "jsoneachrow" => Ok(FileFormat::NdJson),
+            "avro" => Ok(FileFormat::Avro),
+            other => Err(format!(
+                "unsupported file format '{}', expected one of: csv, parquet, ndjson, avro",
+                other
+            )),
+        }
+    }
+}
+
+/// CSV dialect options controlling how a raw line is split into fields. Plugs into a real
+/// `csv::Reader` instead of a naive `split(',')`, so quoted fields containing the delimiter
+/// (or an escaped quote) parse correctly.
+#[derive(Clone, Copy, Debug)]
+struct CsvDialect {
+    delimiter: u8,
+    quote: u8,
+    escape: Option<u8>,
+    header: bool,
+}
+
+impl CsvDialect {
+    fn from_args(args: &ArgMatches) -> Result<CsvDialect> {
+        Ok(CsvDialect {
+            delimiter: parse_dialect_byte(args.value_of("delimiter").unwrap_or(","), "--delimiter")?,
+            quote: parse_dialect_byte(args.value_of("quote").unwrap_or("\""), "--quote")?,
+            escape: args.value_of("escape").map(|s| parse_dialect_byte(s, "--escape")).transpose()?,
+            header: args.is_present("header"),
+        })
+    }
+
+    fn reader_builder(&self) -> csv::ReaderBuilder {
+        let mut builder = csv::ReaderBuilder::new();
+        builder.delimiter(self.delimiter).quote(self.quote).has_headers(false).flexible(true);
+        if let Some(escape) = self.escape {
+            // The `csv` crate only consults a custom escape byte once doubled-quote handling
+            // is turned off; otherwise it silently keeps parsing quotes via doubling.
+            builder.escape(Some(escape)).double_quote(false);
+        }
+        builder
+    }
+}
+
+/// Parse a single-byte CLI option (e.g. `--delimiter ';'`) into the raw byte the `csv` crate
+/// expects, rejecting multi-byte strings with a clear message rather than silently truncating.
+fn parse_dialect_byte(s: &str, flag: &str) -> Result<u8> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 1 {
+        return Err(CliError::Unknown(format!("{} expects a single ascii character, got '{}'", flag, s)));
+    }
+    Ok(bytes[0])
+}
+
+/// Read one logical record off `reader`: for CSV, physical lines are merged until the
+/// accumulated text has a balanced (even) count of `dialect.quote` bytes, so a quoted field
+/// containing an embedded newline (e.g. `"multi\nline"`) is read as a single record instead
+/// of being torn across two `next_line()` reads. This is a quote-parity heuristic rather than
+/// a full dialect-aware scan (it doesn't special-case `dialect.escape`-escaped quotes), but it
+/// covers the common doubled-quote case `parse_csv_line`'s `csv::Reader` already expects.
+/// Non-CSV formats read exactly one physical line, unchanged.
+async fn next_record<S>(
+    reader: &mut common_base::tokio::io::Lines<S>,
+    format: FileFormat,
+    dialect: &CsvDialect,
+) -> Result<Option<String>>
+where S: AsyncBufRead + Unpin,
+{
+    let first = match reader.next_line().await? {
+        Some(line) => line,
+        None => return Ok(None),
+    };
+    if format != FileFormat::Csv {
+        return Ok(Some(first));
+    }
+    let mut record = first;
+    while record.bytes().filter(|&b| b == dialect.quote).count() % 2 != 0 {
+        match reader.next_line().await? {
+            Some(next) => {
+                record.push('\n');
+                record.push_str(&next);
+            }
+            None => break,
+        }
+    }
+    Ok(Some(record))
+}
+
+/// Split one CSV line into fields using the configured dialect.
+fn parse_csv_line(dialect: &CsvDialect, line: &str) -> Result<Vec<String>> {
+    let mut reader = dialect.reader_builder().from_reader(line.as_bytes());
+    let mut record = csv::StringRecord::new();
+    reader
+        .read_record(&mut record)
+        .map_err(|e| CliError::Unknown(format!("cannot parse csv row: {:?}", e)))?;
+    Ok(record.iter().map(|field| field.to_string()).collect())
+}
 
-    fn from_str(s: &str) -> std::result::Result<FileFormat, &'static str> {
-        match s {
-            "csv" => Ok(FileFormat::CSV),
-            _ => Err("no match for profile"),
+/// Render one CSV field as a SQL literal: an empty field or `null` (any case) becomes `NULL`,
+/// numeric-looking tokens pass through bare (matching `ndjson_line_to_tuple`'s convention),
+/// everything else is single-quoted and escaped so strings containing the delimiter or a
+/// stray quote survive the round trip.
+fn csv_field_to_sql(field: &str) -> String {
+    if field.is_empty() || field.eq_ignore_ascii_case("null") {
+        "NULL".to_string()
+    } else if field.parse::<f64>().is_ok() {
+        field.to_string()
+    } else {
+        format!("'{}'", field.replace('\'', "''"))
+    }
+}
+
+fn csv_fields_to_tuple(fields: &[String]) -> String {
+    format!("({})", fields.iter().map(|f| csv_field_to_sql(f)).join(", "))
+}
+
+/// What to do with a malformed row. `Abort` stops the load at the first malformed row;
+/// `Skip` and `Log` count each one against `--max-errors` and abort once it is exceeded.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum OnError {
+    Abort,
+    Skip,
+    Log,
+}
+
+impl FromStr for OnError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<OnError, String> {
+        match s.to_ascii_lowercase().as_str() {
+            "abort" => Ok(OnError::Abort),
+            "skip" => Ok(OnError::Skip),
+            "log" => Ok(OnError::Log),
+            other => Err(format!("unsupported --on-error '{}', expected one of: abort, skip, log", other)),
         }
     }
 }
 
+/// Error-tolerance knobs for row-oriented ingestion: how many malformed rows to tolerate
+/// before aborting the load, and what to do with each one under that threshold.
+#[derive(Clone)]
+struct ErrorPolicy {
+    on_error: OnError,
+    max_errors: usize,
+    rejects_path: Option<PathBuf>,
+}
+
+impl ErrorPolicy {
+    fn from_args(args: &ArgMatches) -> Result<ErrorPolicy> {
+        let on_error: OnError = args
+            .value_of_t("on-error")
+            .map_err(|e| CliError::Unknown(format!("{}", e)))?;
+        let max_errors = args.value_of("max-errors").unwrap_or("0").parse::<usize>()
+            .map_err(|e| CliError::Unknown(format!("invalid --max-errors: {:?}", e)))?;
+        let rejects_path = args.value_of("rejects-file").map(PathBuf::from).or_else(|| {
+            args.value_of("table").map(|table| PathBuf::from(format!("{}.rejects", table)))
+        });
+        Ok(ErrorPolicy { on_error, max_errors, rejects_path })
+    }
+}
+
+/// Append rejected rows to the configured rejects file, if `--on-error log` produced any. A
+/// run with no rejected rows never touches the filesystem.
+fn flush_rejects(path: &Option<PathBuf>, rejects: &[String]) -> Result<()> {
+    if rejects.is_empty() {
+        return Ok(());
+    }
+    let path = match path {
+        Some(p) => p,
+        None => return Ok(()),
+    };
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| CliError::Unknown(format!("cannot open rejects file {}: {:?}", path.display(), e)))?;
+    for line in rejects {
+        writeln!(file, "{}", line)
+            .map_err(|e| CliError::Unknown(format!("cannot write rejects file {}: {:?}", path.display(), e)))?;
+    }
+    Ok(())
+}
+
+/// Parse a single NDJSON line into an `(v1, v2, ...)` tuple, ordering fields according
+/// to `columns` so the values line up with the target table's declared schema.
+fn ndjson_line_to_tuple(line: &str, columns: &[String]) -> Result<String> {
+    let value: serde_json::Value = serde_json::from_str(line.trim())
+        .map_err(|e| CliError::Unknown(format!("cannot parse ndjson line: {:?}", e)))?;
+    let fields = columns
+        .iter()
+        .map(|col| match value.get(col) {
+            Some(serde_json::Value::String(s)) => format!("'{}'", s.replace('\'', "''")),
+            Some(v) if v.is_null() => "NULL".to_string(),
+            Some(v) => v.to_string(),
+            None => "NULL".to_string(),
+        })
+        .join(", ");
+    Ok(format!("({})", fields))
+}
+
+/// Compute the permutation that reorders a columnar file's own field order to match the
+/// declared/inferred `columns` order, so `--schema`'s (or the table's) column order wins
+/// over whatever order happens to be baked into the Parquet/Avro file. An empty `columns`
+/// (no declared schema) keeps the file's own order.
+fn reorder_indices(file_fields: &[String], columns: &[String]) -> Result<Vec<usize>> {
+    if columns.is_empty() {
+        return Ok((0..file_fields.len()).collect());
+    }
+    columns
+        .iter()
+        .map(|col| {
+            file_fields.iter().position(|f| f == col).ok_or_else(|| {
+                CliError::Unknown(format!("column '{}' not found in file schema: {:?}", col, file_fields))
+            })
+        })
+        .collect()
+}
+
+/// Decode a whole Parquet file (every row group) into INSERT tuples, reordered to match `columns`.
+fn parquet_batch_to_tuples(reader: impl std::io::Read + std::io::Seek, columns: &[String]) -> Result<Vec<String>> {
+    let mut reader = reader;
+    let metadata = parquet_read::read_metadata(&mut reader)
+        .map_err(|e| CliError::Unknown(format!("cannot read parquet metadata: {:?}", e)))?;
+    let schema = parquet_read::infer_schema(&metadata)
+        .map_err(|e| CliError::Unknown(format!("cannot infer parquet schema: {:?}", e)))?;
+    let file_fields: Vec<String> = schema.fields.iter().map(|f| f.name.clone()).collect();
+    let order = reorder_indices(&file_fields, columns)?;
+    let mut tuples = vec![];
+    for batch in parquet_read::FileReader::new(reader, metadata.row_groups, schema, None, None, None) {
+        let chunk = batch.map_err(|e| CliError::Unknown(format!("cannot decode parquet row group: {:?}", e)))?;
+        tuples.extend(chunk_to_tuples(chunk.arrays(), &order));
+    }
+    Ok(tuples)
+}
+
+/// Decode one Avro object container into INSERT tuples, reordered to match `columns`.
+fn avro_batch_to_tuples(reader: impl std::io::Read, columns: &[String]) -> Result<Vec<String>> {
+    let mut reader = reader;
+    let metadata = avro_read::read_metadata(&mut reader)
+        .map_err(|e| CliError::Unknown(format!("cannot read avro metadata: {:?}", e)))?;
+    let schema = avro_read::infer_schema(&metadata.record)
+        .map_err(|e| CliError::Unknown(format!("cannot infer avro schema: {:?}", e)))?;
+    let file_fields: Vec<String> = schema.fields.iter().map(|f| f.name.clone()).collect();
+    let order = reorder_indices(&file_fields, columns)?;
+    let mut tuples = vec![];
+    for batch in avro_read::Reader::new(reader, metadata, schema.fields, None) {
+        let chunk = batch.map_err(|e| CliError::Unknown(format!("cannot decode avro block: {:?}", e)))?;
+        tuples.extend(chunk_to_tuples(chunk.arrays(), &order));
+    }
+    Ok(tuples)
+}
+
+/// Transpose a columnar chunk into row-major `(v1, v2, ...)` tuples, reading columns in
+/// `order` (see `reorder_indices`) rather than the file's own column order.
+fn chunk_to_tuples(columns: &[Box<dyn Array>], order: &[usize]) -> Vec<String> {
+    let num_rows = columns.first().map(|c| c.len()).unwrap_or(0);
+    (0..num_rows)
+        .map(|row| {
+            let values = order
+                .iter()
+                .map(|&i| arrow2::array::get_display(columns[i].as_ref(), row))
+                .join(", ");
+            format!("({})", values)
+        })
+        .collect()
+}
+
+fn infer_schema_enabled(args: &ArgMatches) -> bool {
+    !args.is_present("no-infer-schema")
+}
+
+/// A per-column type lattice used while inferring a schema from sample rows. Variants are
+/// declared in widening order so `Ord` gives us `widen` for free.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum InferredType {
+    UInt8,
+    UInt64,
+    Int64,
+    Float64,
+    String,
+}
+
+impl InferredType {
+    fn widen(self, other: InferredType) -> InferredType {
+        self.max(other)
+    }
+
+    fn of(token: &str) -> InferredType {
+        if token.parse::<u8>().is_ok() {
+            InferredType::UInt8
+        } else if token.parse::<u64>().is_ok() {
+            InferredType::UInt64
+        } else if token.parse::<i64>().is_ok() {
+            InferredType::Int64
+        } else if token.parse::<f64>().is_ok() {
+            InferredType::Float64
+        } else {
+            InferredType::String
+        }
+    }
+
+    fn sql_name(self, nullable: bool) -> String {
+        let base = match self {
+            InferredType::UInt8 => "uint8",
+            InferredType::UInt64 => "uint64",
+            InferredType::Int64 => "int64",
+            InferredType::Float64 => "float64",
+            InferredType::String => "String",
+        };
+        if nullable {
+            format!("Nullable({})", base)
+        } else {
+            base.to_string()
+        }
+    }
+}
+
+/// Tokenize one sample row into positional column values, for type inference only.
+fn tokenize_sample_row(format: FileFormat, row: &str, columns: &[String], dialect: &CsvDialect) -> Vec<String> {
+    match format {
+        FileFormat::Csv => parse_csv_line(dialect, row).unwrap_or_else(|_| vec![String::new(); columns.len()]),
+        FileFormat::NdJson => {
+            let value: serde_json::Value = match serde_json::from_str(row.trim()) {
+                Ok(v) => v,
+                Err(_) => return vec![String::new(); columns.len()],
+            };
+            columns
+                .iter()
+                .map(|col| match value.get(col) {
+                    Some(serde_json::Value::Null) | None => String::new(),
+                    Some(serde_json::Value::String(s)) => s.clone(),
+                    Some(v) => v.to_string(),
+                })
+                .collect()
+        }
+        FileFormat::Parquet | FileFormat::Avro => unreachable!("inference only supports row formats"),
+    }
+}
+
+/// Sample up to `sample_size` non-empty rows from `reader` and infer a `Schema` from them.
+/// Unlike the explicit `--schema` flag, sampled rows are consumed from the stream, so the
+/// caller must replay the returned `buffered` rows before resuming normal reads.
+async fn infer_schema<S>(
+    reader: &mut common_base::tokio::io::Lines<S>,
+    format: FileFormat,
+    sample_size: usize,
+    dialect: &CsvDialect,
+    header: Option<Vec<String>>,
+) -> Result<(Schema, Vec<String>, Vec<String>)>
+where S: AsyncBufRead + Unpin,
+{
+    let mut buffered = vec![];
+    while buffered.len() < sample_size {
+        match next_record(reader, format, dialect).await? {
+            Some(line) if !line.trim().is_empty() => buffered.push(line),
+            Some(_) => {}
+            None => break,
+        }
+    }
+    if buffered.is_empty() {
+        return Err(CliError::Unknown("cannot infer schema: input is empty".to_string()));
+    }
+
+    let (columns, rows): (Vec<String>, &[String]) = match (format, header) {
+        (FileFormat::Csv, Some(header_tokens)) => (header_tokens, &buffered[..]),
+        (FileFormat::Csv, None) => {
+            // No explicit `--header`: fall back to guessing from the first sampled row.
+            let header_tokens = parse_csv_line(dialect, &buffered[0])?;
+            let looks_like_header = header_tokens.iter().all(|t| InferredType::of(t) == InferredType::String);
+            if looks_like_header {
+                (header_tokens, &buffered[1..])
+            } else {
+                ((0..header_tokens.len()).map(|i| format!("col{}", i)).collect(), &buffered[..])
+            }
+        }
+        (FileFormat::NdJson, _) => {
+            let mut names = std::collections::BTreeSet::new();
+            for line in &buffered {
+                if let Ok(serde_json::Value::Object(map)) = serde_json::from_str::<serde_json::Value>(line) {
+                    names.extend(map.keys().cloned());
+                }
+            }
+            (names.into_iter().collect(), &buffered[..])
+        }
+        (FileFormat::Parquet, _) | (FileFormat::Avro, _) => unreachable!("inference only supports row formats"),
+    };
+
+    let mut types = vec![InferredType::UInt8; columns.len()];
+    let mut nullable = vec![false; columns.len()];
+    for row in rows {
+        for (i, token) in tokenize_sample_row(format, row, &columns, dialect).iter().enumerate() {
+            if i >= types.len() {
+                continue;
+            }
+            if token.is_empty() || token.eq_ignore_ascii_case("null") {
+                nullable[i] = true;
+            } else {
+                types[i] = types[i].widen(InferredType::of(token));
+            }
+        }
+    }
+
+    let mut schema = BTreeMap::new();
+    for ((name, ty), null) in columns.iter().cloned().zip(types.into_iter()).zip(nullable.into_iter()) {
+        schema.insert(name, ty.sql_name(null));
+    }
+    // Replay only the data rows, not a leading header row consumed above, so a schema that
+    // got its column names from the header doesn't also get the header as its first INSERT.
+    Ok((Schema { schema }, columns, rows.to_vec()))
+}
+
 pub struct Schema {
     schema: BTreeMap<String, String>
 }
@@ -120,9 +561,10 @@ impl LoadCommand {
             )
             .arg(
                 Arg::new("format").long("format")
-                    .about("the format of file, support csv")
+                    .about("the format of file, one of: csv, parquet, ndjson, avro")
                     .takes_value(true)
                     .required(false)
+                    .possible_values(&["csv", "parquet", "ndjson", "avro"])
                     .default_value("csv"),
             )
             .arg(
@@ -134,7 +576,10 @@ impl LoadCommand {
             )
             .arg(
                 Arg::new("load")
-                    .about("file to get loaded for example foo.csv")
+                    .about("file, directory, glob, http(s):// URL, or s3://bucket/key or gs://bucket/key object \
+                    to get loaded, for example foo.csv, data/ or 'data/2021-*/*.csv'. s3:// and gs:// objects \
+                    must be publicly readable: bendctl does not yet sign requests or resolve credentials for \
+                    them, so a private object fails with an HTTP 403")
                     .takes_value(true)
                     .required(false),
             )
@@ -145,6 +590,80 @@ impl LoadCommand {
                     .takes_value(true)
                     .required(false),
             )
+            .arg(
+                Arg::new("infer-schema").long("infer-schema")
+                    .about("infer a schema from the input when --schema is not given and the table does not exist yet (csv/ndjson only)")
+                    .takes_value(false)
+                    .required(false),
+            )
+            .arg(
+                Arg::new("no-infer-schema").long("no-infer-schema")
+                    .about("disable schema inference, failing instead when no --schema is given and the table does not exist")
+                    .takes_value(false)
+                    .required(false)
+                    .conflicts_with("infer-schema"),
+            )
+            .arg(
+                Arg::new("sample-size").long("sample-size")
+                    .about("number of rows to sample for schema inference")
+                    .takes_value(true)
+                    .required(false)
+                    .default_value("1000"),
+            )
+            .arg(
+                Arg::new("max-concurrency").long("max-concurrency")
+                    .about("maximum number of files to load concurrently when --load is a directory or a glob")
+                    .takes_value(true)
+                    .required(false)
+                    .default_value("4"),
+            )
+            .arg(
+                Arg::new("delimiter").long("delimiter")
+                    .about("csv field delimiter (csv only)")
+                    .takes_value(true)
+                    .required(false)
+                    .default_value(","),
+            )
+            .arg(
+                Arg::new("quote").long("quote")
+                    .about("csv quote character (csv only)")
+                    .takes_value(true)
+                    .required(false)
+                    .default_value("\""),
+            )
+            .arg(
+                Arg::new("escape").long("escape")
+                    .about("csv escape character, unset by default (csv only)")
+                    .takes_value(true)
+                    .required(false),
+            )
+            .arg(
+                Arg::new("header").long("header")
+                    .about("treat the first csv row as a header of column names instead of data (csv only)")
+                    .takes_value(false)
+                    .required(false),
+            )
+            .arg(
+                Arg::new("max-errors").long("max-errors")
+                    .about("number of malformed rows tolerated with --on-error skip or log before aborting the load")
+                    .takes_value(true)
+                    .required(false)
+                    .default_value("0"),
+            )
+            .arg(
+                Arg::new("on-error").long("on-error")
+                    .about("what to do with a malformed row: abort, skip, or log (then skip)")
+                    .takes_value(true)
+                    .required(false)
+                    .possible_values(&["abort", "skip", "log"])
+                    .default_value("abort"),
+            )
+            .arg(
+                Arg::new("rejects-file").long("rejects-file")
+                    .about("file malformed rows are appended to when --on-error log is set (default: <table>.rejects)")
+                    .takes_value(true)
+                    .required(false),
+            )
             .arg(
                 Arg::new("table").long("table")
                 .about("database table")
@@ -157,58 +676,169 @@ impl LoadCommand {
     async fn local_exec_match(&self, writer: &mut Writer, args: &ArgMatches) -> Result<()> {
         match self.local_exec_precheck(args).await {
             Ok(_) => {
-                 match args.value_of("load") {
-                    Some(val) => {
-                        if Path::new(val).exists() {
-                            let buffer =
-                                std::fs::read(Path::new(val)).expect("cannot read query from file");
-                            String::from_utf8_lossy(&*buffer).to_string();
-                        }
+                let format: FileFormat = args
+                    .value_of_t("format")
+                    .map_err(|e| CliError::Unknown(format!("{}", e)))?;
+                let dialect = CsvDialect::from_args(args)?;
+                let policy = ErrorPolicy::from_args(args)?;
+                let table = args.value_of("table").unwrap();
+                let schema = args.value_of("schema");
+                let mut columns: Vec<String> = match schema {
+                    Some(_) => args
+                        .value_of_t::<Schema>("schema")
+                        .expect("cannot build schema")
+                        .schema
+                        .keys()
+                        .cloned()
+                        .collect(),
+                    None => vec![],
+                };
+                let status = Status::read(self.conf.clone())?;
+                let (cli, url) = build_query_endpoint(&status)?;
+                let load_arg = args.value_of("load");
+
+                // NDJSON tuples are built by looking each field up by column name (see
+                // `ndjson_line_to_tuple`), unlike CSV's positional fields, so an empty
+                // `columns` is only safe there when the table doesn't exist yet and schema
+                // inference is about to fill it in. If the table already exists, fetch its
+                // column list now rather than silently emitting `()` for every row below.
+                if columns.is_empty() && format == FileFormat::NdJson {
+                    if table_exists(&status, Some(table)).await.is_ok() {
+                        columns = fetch_table_columns(&status, table).await?;
+                    } else if !infer_schema_enabled(args) {
+                        return Err(CliError::Unknown(format!(
+                            "table {} does not exist and neither --schema nor schema inference was given",
+                            table
+                        )));
                     }
+                }
+
+                match load_arg {
                     None => {
+                        // No --load: stream a single INSERT source from stdin.
+                        if !format.is_row_oriented() {
+                            return Err(CliError::Unknown(format!("{:?} requires a local --load file, not stdin", format)));
+                        }
                         let io = common_base::tokio::io::stdin();
                         let mut reader = BufReader::new(io).lines();
-                        for i in 0..args.value_of("skip-head-lines").unwrap_or("0").parse::<usize>().unwrap() {
-                            if let None = reader.next_line().await? {
-                                return Ok(())
-                            }
-                        }
-                        let table = args.value_of("table").unwrap();
-                        let schema = args.value_of("schema");
-                        let table_format = match schema {
-                            Some(s) => {
-                                let schema : Schema = args.value_of_t("schema").expect("cannot build schema");
-                                format!("{} ({})", table, schema.schema.keys().into_iter().join(", "))
-                            }
-                            None => {
+                        skip_lines(&mut reader, skip_head_lines(args), format, &dialect).await?;
+                        let header = take_header(&mut reader, format, &dialect).await?;
+                        ingest_single_source(reader, "stdin", format, &status, args, table, &mut columns, &cli, &url, &dialect, &policy, header, writer).await?;
+                    }
+                    Some(location) if is_remote_uri(location) => {
+                        // A single remote object (http(s)/s3/gs), with retry-with-backoff on
+                        // fetch: CSV/NDJSON are streamed incrementally, while Parquet/Avro
+                        // objects are read whole into memory before decoding.
+                        let progress = Arc::new(Mutex::new(ProgressValues::default()));
+                        if format.is_row_oriented() {
+                            let mut reader = open_stream(location, progress.clone()).await?.lines();
+                            skip_lines(&mut reader, skip_head_lines(args), format, &dialect).await?;
+                            let header = take_header(&mut reader, format, &dialect).await?;
+                            ingest_single_source(reader, location, format, &status, args, table, &mut columns, &cli, &url, &dialect, &policy, header, writer).await?;
+                            report_progress(writer, location, &progress);
+                        } else {
+                            let table_format = if columns.is_empty() {
                                 table.to_string()
-                            }
-                        };
-                        let status = Status::read(self.conf.clone())?;
-                        let (cli, url) = build_query_endpoint(&status)?;
-                        loop {
-                            let mut batch = vec![];
-                            for _ in 0..100_000 {
-                                if let Some(line) = reader.next_line().await? {
-                                    batch.push(line);
-                                } else {
-                                    break;
+                            } else {
+                                format!("{} ({})", table, columns.iter().join(", "))
+                            };
+                            let buffer = read_all_from_stream(location, progress.clone()).await?;
+                            report_progress(writer, location, &progress);
+                            let tuples = match format {
+                                FileFormat::Parquet => parquet_batch_to_tuples(std::io::Cursor::new(buffer), &columns)?,
+                                FileFormat::Avro => avro_batch_to_tuples(std::io::Cursor::new(buffer), &columns)?,
+                                FileFormat::Csv | FileFormat::NdJson => unreachable!("row formats are handled above"),
+                            };
+                            for chunk in tuples.chunks(100_000) {
+                                let query = format!("INSERT INTO {} VALUES {}", table_format, chunk.iter().join(", "));
+                                if let Err(e) = execute_query_json(&cli, &url, query).await {
+                                    writer.write_err(format!("{}: cannot insert data into {}, error: {:?}", location, table, e))
                                 }
                             }
-                            if batch.is_empty() {
-                                break;
+                        }
+                    }
+                    Some(pattern) => {
+                        let files = list_load_files(pattern, format)?;
+                        // One or more local files (a single path, a directory, or a glob):
+                        // loaded concurrently up to --max-concurrency, sharing one endpoint.
+                        let mut first_file_replay = vec![];
+                        // The reader already positioned past the sampled rows for file 0, if
+                        // inference ran: reused as-is by the task below so those rows aren't
+                        // read a second time from a freshly (re)opened file handle.
+                        let mut first_file_reader = None;
+                        if columns.is_empty() && table_exists(&status, Some(table)).await.is_err() {
+                            // local_exec_precheck already rejected the table-doesn't-exist
+                            // combinations that can't reach here: non-row-oriented formats,
+                            // and row-oriented formats with inference disabled.
+                            let sample_size = args.value_of("sample-size").unwrap_or("1000").parse::<usize>()
+                                .map_err(|e| CliError::Unknown(format!("invalid --sample-size: {:?}", e)))?;
+                            let mut first_reader = file_lines(&files[0]).await?;
+                            skip_lines(&mut first_reader, skip_head_lines(args), format, &dialect).await?;
+                            let header = take_header(&mut first_reader, format, &dialect).await?;
+                            let (inferred, inferred_columns, sampled_rows) = infer_schema(&mut first_reader, format, sample_size, &dialect, header).await?;
+                            create_table_if_not_exists(&status, Some(table), inferred).await?;
+                            columns = inferred_columns;
+                            first_file_replay = sampled_rows;
+                            first_file_reader = Some(first_reader);
+                        }
+                        let table_format = if columns.is_empty() {
+                            table.to_string()
+                        } else {
+                            format!("{} ({})", table, columns.iter().join(", "))
+                        };
+                        let max_concurrency = args.value_of("max-concurrency").unwrap_or("4").parse::<usize>()
+                            .map_err(|e| CliError::Unknown(format!("invalid --max-concurrency: {:?}", e)))?
+                            .max(1);
+
+                        let per_file_skip = skip_head_lines(args);
+                        let tasks = files.into_iter().enumerate().map(|(i, path)| {
+                            let cli = cli.clone();
+                            let url = url.clone();
+                            let table_format = table_format.clone();
+                            let columns = columns.clone();
+                            let dialect = dialect;
+                            let policy = policy.clone();
+                            let initial_batch = if i == 0 { std::mem::take(&mut first_file_replay) } else { vec![] };
+                            let reused_reader = if i == 0 { first_file_reader.take() } else { None };
+                            async move {
+                                let outcome = async {
+                                    if format.is_row_oriented() {
+                                        let reader = match reused_reader {
+                                            Some(reader) => reader,
+                                            None => {
+                                                let mut reader = file_lines(&path).await?;
+                                                skip_lines(&mut reader, per_file_skip, format, &dialect).await?;
+                                                // Column names for this run already came from
+                                                // --schema, an existing table, or file 0's
+                                                // inference above; with CSV `--header`, each
+                                                // subsequent file's own header line is simply
+                                                // discarded here (otherwise nothing is consumed).
+                                                take_header(&mut reader, format, &dialect).await?;
+                                                reader
+                                            }
+                                        };
+                                        ingest_rows(reader, initial_batch, format, &columns, &table_format, table, &cli, &url, &dialect, &policy).await
+                                    } else {
+                                        ingest_columnar(&path, format, &columns, &table_format, table, &cli, &url).await
+                                    }
+                                }.await;
+                                (path, outcome)
                             }
-                            let values = batch.into_iter().par_bridge().map(|e| format!("({})", e.trim())).filter(|e| !e.trim().is_empty() ).reduce_with(|a, b | format!("{}, {}", a, b));
-                            if let Some(values) = values {
-                                let query = format!("INSERT INTO {} VALUES {}", table_format, values);
-                                if let Err(e) = execute_query_json(&cli, &url, query).await {
-                                    writer.write_err(format!("cannot insert data into {}, error: {:?}", table, e))
+                        });
+                        let results: Vec<(PathBuf, Result<Vec<String>>)> =
+                            stream::iter(tasks).buffer_unordered(max_concurrency).collect().await;
+                        for (path, outcome) in results {
+                            match outcome {
+                                Ok(errors) => {
+                                    for e in errors {
+                                        writer.write_err(format!("{}: {}", path.display(), e));
+                                    }
                                 }
+                                Err(e) => writer.write_err(format!("{}: {:?}", path.display(), e)),
                             }
-
                         }
                     }
-                };
+                }
                 Ok(())
             }
             Err(e) => {
@@ -231,7 +861,27 @@ impl LoadCommand {
         // TODO typecheck
         if args.value_of("schema").is_none() {
             if let Err(e) = table_exists(&status, args.value_of("table")).await {
-                return Err(e)
+                // No --schema and no existing table: fall through to schema inference in
+                // local_exec_match for row-oriented formats, unless the caller opted out.
+                // Parquet/Avro can never be inferred (see `infer_schema`'s `unreachable!` for
+                // those formats), so that combination is rejected here with the message the
+                // user should actually see, rather than falling through to the generic
+                // `table_exists` error and leaving local_exec_match's own checks unreachable.
+                let format: FileFormat = args.value_of_t("format").unwrap_or(FileFormat::Csv);
+                let table = args.value_of("table").unwrap_or("");
+                if !format.is_row_oriented() {
+                    return Err(CliError::Unknown(format!(
+                        "table {} does not exist; {:?} requires --schema or an existing table",
+                        table, format
+                    )));
+                }
+                if infer_schema_enabled(args) {
+                    return Ok(())
+                }
+                return Err(CliError::Unknown(format!(
+                    "table {} does not exist and neither --schema nor schema inference was given",
+                    table
+                )));
             }
             Ok(())
         } else {
@@ -247,32 +897,377 @@ impl LoadCommand {
     }
 }
 
-async fn build_reader<R>(load: Option<&str>) -> BufReader<R>
-where R: AsyncRead
+/// Expand a `--load` argument into a sorted file list: a literal path, a directory (listed
+/// one level deep), or a glob like `data/2021-*/*.csv`. Every matched file must carry an
+/// extension consistent with `--format`, so a stray file of a different format in the same
+/// directory/glob is rejected upfront rather than failing confusingly mid-load.
+fn list_load_files(pattern: &str, format: FileFormat) -> Result<Vec<PathBuf>> {
+    let direct = Path::new(pattern);
+    let mut files: Vec<PathBuf> = if direct.is_dir() {
+        std::fs::read_dir(direct)
+            .map_err(|e| CliError::Unknown(format!("cannot list directory {}: {:?}", pattern, e)))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|p| p.is_file())
+            .collect()
+    } else {
+        glob(pattern)
+            .map_err(|e| CliError::Unknown(format!("invalid glob pattern '{}': {:?}", pattern, e)))?
+            .filter_map(|entry| entry.ok())
+            .filter(|p| p.is_file())
+            .collect()
+    };
+    files.sort();
+    if files.is_empty() {
+        return Err(CliError::Unknown(format!("no files matched '{}'", pattern)));
+    }
+    let expected = format.extensions();
+    let mismatched: Vec<&PathBuf> = files
+        .iter()
+        .filter(|p| {
+            !p.extension()
+                .and_then(|e| e.to_str())
+                .map(|e| expected.contains(&e.to_ascii_lowercase().as_str()))
+                .unwrap_or(false)
+        })
+        .collect();
+    if !mismatched.is_empty() {
+        return Err(CliError::Unknown(format!(
+            "'{}' matched files that don't look like {:?} (expected extension {:?}): {:?}",
+            pattern, format, expected, mismatched
+        )));
+    }
+    Ok(files)
+}
+
+async fn file_lines(path: &Path) -> Result<common_base::tokio::io::Lines<BufReader<File>>> {
+    let f = File::open(path)
+        .await
+        .map_err(|e| CliError::Unknown(format!("cannot open {}: {:?}", path.display(), e)))?;
+    Ok(BufReader::new(f).lines())
+}
+
+/// Number of lines to drop from the top of each file before ingestion, per `--skip-head-lines`.
+/// Separate from `--header`, which names the first remaining row rather than discarding it.
+fn skip_head_lines(args: &ArgMatches) -> usize {
+    args.value_of("skip-head-lines").unwrap_or("0").parse::<usize>().unwrap_or(0)
+}
+
+async fn skip_lines<S>(
+    reader: &mut common_base::tokio::io::Lines<S>,
+    n: usize,
+    format: FileFormat,
+    dialect: &CsvDialect,
+) -> Result<()>
+where S: AsyncBufRead + Unpin,
+{
+    for _ in 0..n {
+        if next_record(reader, format, dialect).await?.is_none() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Consume and parse the header line declared by `--header` (CSV only), so its column names
+/// can be threaded into schema inference directly instead of being re-guessed from the first
+/// data row. Returns `None` (reader untouched) for non-CSV formats or when `--header` wasn't
+/// given.
+async fn take_header<S>(
+    reader: &mut common_base::tokio::io::Lines<S>,
+    format: FileFormat,
+    dialect: &CsvDialect,
+) -> Result<Option<Vec<String>>>
+where S: AsyncBufRead + Unpin,
+{
+    if format != FileFormat::Csv || !dialect.header {
+        return Ok(None);
+    }
+    match next_record(reader, format, dialect).await? {
+        Some(line) => Ok(Some(parse_csv_line(dialect, &line)?)),
+        None => Ok(None),
+    }
+}
+
+/// Drain a row-oriented reader into INSERT batches of up to 100k tuples at a time,
+/// flushing `initial_batch` (e.g. rows already consumed for schema inference) first.
+/// Malformed rows are handled per `policy`: skipped, logged to a rejects file, or (once
+/// `--max-errors` is exceeded) aborted. Per-row and per-batch failures are otherwise
+/// collected rather than propagated, so one bad file doesn't stop the rest of a
+/// multi-file load.
+async fn ingest_rows<S>(
+    mut reader: common_base::tokio::io::Lines<S>,
+    mut initial_batch: Vec<String>,
+    format: FileFormat,
+    columns: &[String],
+    table_format: &str,
+    table: &str,
+    cli: &Client,
+    url: &str,
+    dialect: &CsvDialect,
+    policy: &ErrorPolicy,
+) -> Result<Vec<String>>
+where S: AsyncBufRead + Unpin,
 {
-    match load {
-        Some(val) => {
-            if Path::new(val).exists() {
-                let f = File::open(val).await.expect("cannot open file: permission denied");
-                return BufReader::new(f)
-            } else if val.starts_with("http://") || val.starts_with("https://") {
-                let res = reqwest::get(val)
-                    .await
-                    .expect("cannot fetch query from url")
-                    .text()
-                    .await
-                    .expect("cannot fetch response body");
-                res
+    let mut errors = vec![];
+    let mut malformed = 0usize;
+    let mut rejects = vec![];
+    loop {
+        let mut batch = std::mem::take(&mut initial_batch);
+        for _ in 0..100_000usize.saturating_sub(batch.len()) {
+            if let Some(line) = next_record(&mut reader, format, dialect).await? {
+                batch.push(line);
             } else {
-                val.to_string()
+                break;
             }
         }
-        None => {
-            let io = common_base::tokio::io::stdin();
-            return BufReader::new(io)
+        if batch.is_empty() {
+            break;
+        }
+        let mut tuples = vec![];
+        let mut abort: Option<CliError> = None;
+        for line in batch {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let parsed = match format {
+                FileFormat::Csv => parse_csv_line(dialect, &line).map(|fields| csv_fields_to_tuple(&fields)),
+                FileFormat::NdJson => ndjson_line_to_tuple(&line, columns),
+                FileFormat::Parquet | FileFormat::Avro => unreachable!("columnar formats are handled separately"),
+            };
+            match parsed {
+                Ok(tuple) => tuples.push(tuple),
+                Err(e) => {
+                    if policy.on_error == OnError::Abort {
+                        abort = Some(e);
+                        break;
+                    }
+                    malformed += 1;
+                    errors.push(format!("skipping malformed {:?} row: {:?}", format, e));
+                    if policy.on_error == OnError::Log {
+                        rejects.push(line);
+                    }
+                    if malformed > policy.max_errors {
+                        abort = Some(CliError::Unknown(format!(
+                            "aborting load: {} malformed rows exceeded --max-errors {}",
+                            malformed, policy.max_errors
+                        )));
+                        break;
+                    }
+                }
+            }
+        }
+        // Flush whatever valid rows were accumulated before the abort (if any), so a
+        // malformed row never throws away good rows that preceded it in the same batch.
+        if !tuples.is_empty() {
+            let query = format!("INSERT INTO {} VALUES {}", table_format, tuples.iter().join(", "));
+            if let Err(e) = execute_query_json(cli, url, query).await {
+                errors.push(format!("cannot insert data into {}, error: {:?}", table, e));
+            }
+        }
+        if let Some(e) = abort {
+            flush_rejects(&policy.rejects_path, &rejects)?;
+            return Err(e);
         }
     }
-    BufReader::new(io)
+    flush_rejects(&policy.rejects_path, &rejects)?;
+    Ok(errors)
+}
+
+/// Decode one Parquet/Avro file into INSERT batches of up to 100k tuples at a time.
+async fn ingest_columnar(
+    path: &Path,
+    format: FileFormat,
+    columns: &[String],
+    table_format: &str,
+    table: &str,
+    cli: &Client,
+    url: &str,
+) -> Result<Vec<String>> {
+    let buffer = std::fs::read(path)
+        .map_err(|e| CliError::Unknown(format!("cannot read {}: {:?}", path.display(), e)))?;
+    let tuples = match format {
+        FileFormat::Parquet => parquet_batch_to_tuples(std::io::Cursor::new(buffer), columns)?,
+        FileFormat::Avro => avro_batch_to_tuples(std::io::Cursor::new(buffer), columns)?,
+        FileFormat::Csv | FileFormat::NdJson => unreachable!("row formats are handled separately"),
+    };
+    let mut errors = vec![];
+    for chunk in tuples.chunks(100_000) {
+        let query = format!("INSERT INTO {} VALUES {}", table_format, chunk.iter().join(", "));
+        if let Err(e) = execute_query_json(cli, url, query).await {
+            errors.push(format!("cannot insert data into {}, error: {:?}", table, e));
+        }
+    }
+    Ok(errors)
+}
+
+fn is_remote_uri(location: &str) -> bool {
+    location.starts_with("http://")
+        || location.starts_with("https://")
+        || location.starts_with("s3://")
+        || location.starts_with("gs://")
+}
+
+/// Turn an `s3://bucket/key` or `gs://bucket/key` URI into a plain HTTPS URL so it can be
+/// fetched the same way as an `http(s)://` source. This reaches publicly-readable objects
+/// only: `AWS_S3_ENDPOINT` picks an S3-compatible endpoint, but there is no SigV4 (or other)
+/// request signing, so a private bucket just comes back as an HTTP 403 from the GET in
+/// `http_stream_with_retry`. Real credential resolution through `Config`/`Status` is left
+/// for when bendctl gains its own profiles.
+fn resolve_object_store_url(location: &str) -> Result<Option<String>> {
+    if let Some(rest) = location.strip_prefix("s3://") {
+        let (bucket, key) = rest.split_once('/').ok_or_else(|| {
+            CliError::Unknown(format!("invalid s3 uri, expected s3://bucket/key: {}", location))
+        })?;
+        let endpoint = std::env::var("AWS_S3_ENDPOINT").unwrap_or_else(|_| "https://s3.amazonaws.com".to_string());
+        return Ok(Some(format!("{}/{}/{}", endpoint.trim_end_matches('/'), bucket, key)));
+    }
+    if let Some(rest) = location.strip_prefix("gs://") {
+        let (bucket, key) = rest.split_once('/').ok_or_else(|| {
+            CliError::Unknown(format!("invalid gs uri, expected gs://bucket/key: {}", location))
+        })?;
+        return Ok(Some(format!("https://storage.googleapis.com/{}/{}", bucket, key)));
+    }
+    Ok(None)
+}
+
+/// GET `url`, retrying transient failures (connection errors, 5xx) with exponential
+/// backoff, and return the response body as a streaming reader rather than buffering it.
+async fn http_stream_with_retry(url: &str, max_retries: usize) -> Result<impl AsyncRead + Unpin + Send> {
+    let mut attempt = 0usize;
+    loop {
+        match reqwest::get(url).await {
+            Ok(resp) if resp.status().is_success() => {
+                let bytes_stream = resp
+                    .bytes_stream()
+                    .map(|chunk| chunk.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)));
+                return Ok(StreamReader::new(bytes_stream));
+            }
+            Ok(resp) if attempt < max_retries && resp.status().is_server_error() => {
+                attempt += 1;
+                common_base::tokio::time::sleep(std::time::Duration::from_millis(200 * 2u64.pow(attempt as u32))).await;
+            }
+            Ok(resp) => return Err(CliError::Unknown(format!("cannot fetch {}: HTTP {}", url, resp.status()))),
+            Err(e) if attempt < max_retries => {
+                attempt += 1;
+                common_base::tokio::time::sleep(std::time::Duration::from_millis(200 * 2u64.pow(attempt as u32))).await;
+            }
+            Err(e) => return Err(CliError::Unknown(format!("cannot fetch {}: {:?}", url, e))),
+        }
+    }
+}
+
+/// Surface the byte count a `ProgressReader` accumulated into `progress` back to the user,
+/// once a remote source has finished streaming. `open_stream`/`read_all_from_stream` only
+/// update the counter; nothing read it back out before this.
+fn report_progress(writer: &mut Writer, source: &str, progress: &Arc<Mutex<ProgressValues>>) {
+    if let Ok(progress) = progress.lock() {
+        writer.write_ok(format!("{}: read {} bytes", source, progress.read_bytes));
+    }
+}
+
+/// Wraps any `AsyncRead` and accumulates the bytes read into a shared `ProgressValues`,
+/// so a multi-gigabyte remote load can report progress instead of loading silently.
+struct ProgressReader<R> {
+    inner: R,
+    progress: Arc<Mutex<ProgressValues>>,
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ProgressReader<R> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<std::io::Result<()>> {
+        let before = buf.filled().len();
+        let poll = Pin::new(&mut self.inner).poll_read(cx, buf);
+        if poll.is_ready() {
+            let read = buf.filled().len() - before;
+            if read > 0 {
+                if let Ok(mut progress) = self.progress.lock() {
+                    progress.read_bytes += read;
+                }
+            }
+        }
+        poll
+    }
+}
+
+/// Resolve a `--load` location (local path, `http(s)://`, `s3://`, `gs://`) into a uniform
+/// streaming byte source, so the line/record batch loop can read multi-gigabyte remote
+/// files incrementally instead of buffering the whole object in memory first.
+async fn open_stream(location: &str, progress: Arc<Mutex<ProgressValues>>) -> Result<Box<dyn AsyncBufRead + Unpin + Send>> {
+    if let Some(url) = resolve_object_store_url(location)? {
+        let stream = http_stream_with_retry(&url, 3).await.map_err(|e| {
+            CliError::Unknown(format!(
+                "{:?} (bendctl does not resolve s3://gs:// credentials or sign requests yet; \
+                 only publicly-readable objects can be loaded this way)",
+                e
+            ))
+        })?;
+        return Ok(Box::new(BufReader::new(ProgressReader { inner: stream, progress })));
+    }
+    if location.starts_with("http://") || location.starts_with("https://") {
+        let stream = http_stream_with_retry(location, 3).await?;
+        return Ok(Box::new(BufReader::new(ProgressReader { inner: stream, progress })));
+    }
+    let f = File::open(location)
+        .await
+        .map_err(|e| CliError::Unknown(format!("cannot open {}: {:?}", location, e)))?;
+    Ok(Box::new(BufReader::new(ProgressReader { inner: f, progress })))
+}
+
+/// Same as `open_stream`, but for columnar formats (Parquet/Avro) that need the whole
+/// object decoded at once rather than line by line.
+async fn read_all_from_stream(location: &str, progress: Arc<Mutex<ProgressValues>>) -> Result<Vec<u8>> {
+    let mut reader = open_stream(location, progress).await?;
+    let mut buffer = vec![];
+    reader
+        .read_to_end(&mut buffer)
+        .await
+        .map_err(|e| CliError::Unknown(format!("cannot read {}: {:?}", location, e)))?;
+    Ok(buffer)
+}
+
+/// Infer a schema from `reader` if needed, then drain it into INSERT batches, reporting
+/// per-row/per-batch failures through `writer` tagged with `source`.
+async fn ingest_single_source<S>(
+    mut reader: common_base::tokio::io::Lines<S>,
+    source: &str,
+    format: FileFormat,
+    status: &Status,
+    args: &ArgMatches,
+    table: &str,
+    columns: &mut Vec<String>,
+    cli: &Client,
+    url: &str,
+    dialect: &CsvDialect,
+    policy: &ErrorPolicy,
+    header: Option<Vec<String>>,
+    writer: &mut Writer,
+) -> Result<()>
+where S: AsyncBufRead + Unpin,
+{
+    let mut replay = vec![];
+    if columns.is_empty() && table_exists(status, Some(table)).await.is_err() {
+        if !infer_schema_enabled(args) {
+            return Err(CliError::Unknown(format!(
+                "table {} does not exist and neither --schema nor schema inference was given",
+                table
+            )));
+        }
+        let sample_size = args.value_of("sample-size").unwrap_or("1000").parse::<usize>()
+            .map_err(|e| CliError::Unknown(format!("invalid --sample-size: {:?}", e)))?;
+        let (inferred, inferred_columns, sampled_rows) = infer_schema(&mut reader, format, sample_size, dialect, header).await?;
+        create_table_if_not_exists(status, Some(table), inferred).await?;
+        *columns = inferred_columns;
+        replay = sampled_rows;
+    }
+    let table_format = if columns.is_empty() {
+        table.to_string()
+    } else {
+        format!("{} ({})", table, columns.iter().join(", "))
+    };
+    for e in ingest_rows(reader, replay, format, columns, &table_format, table, cli, url, dialect, policy).await? {
+        writer.write_err(format!("{}: {}", source, e));
+    }
+    Ok(())
 }
 
 async fn table_exists(status: &Status, table: Option<&str>) -> Result<()>  {
@@ -292,6 +1287,27 @@ async fn table_exists(status: &Status, table: Option<&str>) -> Result<()>  {
     Ok(())
 }
 
+/// Fetch the column names of an existing table, in declared order, via `DESC TABLE`. Used
+/// to populate NDJSON's `columns` (which must match field names by key, not position) when
+/// loading into a table that already exists and no `--schema` was given.
+async fn fetch_table_columns(status: &Status, table: &str) -> Result<Vec<String>> {
+    let (cli, url) = build_query_endpoint(status)?;
+    let query = format!("DESC TABLE {};", table);
+    let (col, data, _) = execute_query_json(&cli, &url, query).await?;
+    if col.is_none() || data.is_none() {
+        return Err(CliError::Unknown(format!("cannot describe table {}: empty response", table)));
+    }
+    data.unwrap()
+        .into_iter()
+        .map(|row| {
+            row.get(0)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .ok_or_else(|| CliError::Unknown(format!("cannot parse column name from DESC TABLE {} response", table)))
+        })
+        .collect()
+}
+
 async fn create_table_if_not_exists(status: &Status, table: Option<&str>, schema: Schema) -> Result<()> {
     return match table_exists(status, table).await {
         Ok(_) => {